@@ -0,0 +1,149 @@
+use crate::{Storage, WriteBehavior};
+
+const fn align_down(value: u32, align: u32) -> u32 {
+    value - value % align
+}
+
+const fn align_up(value: u32, align: u32) -> u32 {
+    align_down(value + align - 1, align)
+}
+
+/// The `WRITE_SIZE` this adapter exposes for an inner storage `S`.
+///
+/// Byte-granular writes can only be emulated with a read-modify-write on
+/// `InfiniteAnd`/`InfiniteDirect` storages, since those are the only
+/// behaviors that allow writing the same bytes an unbounded number of times.
+/// For `Once`/`TwiceSecondZero`/`TwiceAnd` storages, `S::WRITE_SIZE` is kept
+/// as-is, so the inner storage's own alignment check rejects byte-granular
+/// writes at runtime.
+const fn aligned_write_size<S: Storage>() -> usize {
+    match S::WRITE_BEHAVIOR {
+        WriteBehavior::InfiniteAnd | WriteBehavior::InfiniteDirect => 1,
+        WriteBehavior::Once | WriteBehavior::TwiceSecondZero | WriteBehavior::TwiceAnd => S::WRITE_SIZE,
+    }
+}
+
+/// Emulates byte-granular access over a [`Storage`] with a larger read (and,
+/// where safe, write) granularity.
+///
+/// The [`Storage`] docs say "Ideally the driver can emulate single-byte reads
+/// if the hardware doesn't support it" — this adapter provides that. It
+/// always exposes `READ_SIZE = 1`. Reads round the requested range down/up to
+/// `S::READ_SIZE` boundaries, read into a `BUF`-byte stack buffer, and copy
+/// out the requested slice, iterating when the aligned span exceeds `BUF`.
+///
+/// `WRITE_SIZE` is only relaxed to 1 on `InfiniteAnd`/`InfiniteDirect`
+/// storages, since a read-modify-write is only safe when the same bytes can
+/// be written an unbounded number of times. `BUF` must be a nonzero multiple
+/// of `S::READ_SIZE`, and, when writes are emulated, at least `S::WRITE_SIZE`
+/// — this is checked at const-eval time.
+pub struct Aligned<S, const BUF: usize>(S);
+
+impl<S, const BUF: usize> Aligned<S, BUF> {
+    /// Wrap `inner`, exposing byte-granular reads (and, where safe, writes) over it.
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+impl<S: Storage, const BUF: usize> Storage for Aligned<S, BUF> {
+    type Error = S::Error;
+
+    const READ_SIZE: usize = {
+        assert!(
+            BUF >= S::READ_SIZE && BUF % S::READ_SIZE == 0,
+            "Aligned: BUF must be a nonzero multiple of S::READ_SIZE"
+        );
+        if aligned_write_size::<S>() == 1 {
+            assert!(
+                BUF >= S::WRITE_SIZE,
+                "Aligned: BUF must be at least S::WRITE_SIZE when write emulation is active"
+            );
+        }
+        1
+    };
+    const WRITE_SIZE: usize = aligned_write_size::<S>();
+    const ERASE_SIZE: usize = S::ERASE_SIZE;
+    const ERASE_VALUE: u8 = S::ERASE_VALUE;
+    const WRITE_BEHAVIOR: WriteBehavior = S::WRITE_BEHAVIOR;
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        // Force the const-eval checks on `BUF` that live in `READ_SIZE`.
+        let _ = Self::READ_SIZE;
+
+        let end = offset + bytes.len() as u32;
+        let mut pos = offset;
+        let mut written = 0usize;
+
+        while pos < end {
+            let window_start = align_down(pos, S::READ_SIZE as u32);
+            let window_end = (window_start + BUF as u32).min(align_up(end, S::READ_SIZE as u32));
+
+            let mut buf = [0u8; BUF];
+            self.0
+                .read(window_start, &mut buf[..(window_end - window_start) as usize])
+                .await?;
+
+            let copy_start = (pos - window_start) as usize;
+            let copy_len = (window_end.min(end) - pos) as usize;
+            bytes[written..written + copy_len].copy_from_slice(&buf[copy_start..copy_start + copy_len]);
+
+            written += copy_len;
+            pos += copy_len as u32;
+        }
+
+        Ok(())
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        // Force the const-eval checks on `BUF` that live in `READ_SIZE`.
+        let _ = Self::READ_SIZE;
+
+        if Self::WRITE_SIZE == S::WRITE_SIZE {
+            // No emulation is happening: fall straight through, letting the
+            // inner storage enforce its own alignment.
+            return self.0.write(offset, bytes).await;
+        }
+
+        let end = offset + bytes.len() as u32;
+        let mut pos = offset;
+        let mut read = 0usize;
+
+        while pos < end {
+            let word_start = align_down(pos, S::WRITE_SIZE as u32);
+            let word_end = word_start + S::WRITE_SIZE as u32;
+            let copy_start = (pos - word_start) as usize;
+            let copy_len = (word_end.min(end) - pos) as usize;
+
+            let mut word = [0u8; BUF];
+            let word = &mut word[..S::WRITE_SIZE];
+
+            match S::WRITE_BEHAVIOR {
+                // Untouched bytes are left at ERASE_VALUE, which is the AND identity.
+                WriteBehavior::InfiniteAnd => word.fill(S::ERASE_VALUE),
+                // A direct overwrite must preserve the untouched neighbouring bytes.
+                _ => self.0.read(word_start, word).await?,
+            }
+
+            word[copy_start..copy_start + copy_len].copy_from_slice(&bytes[read..read + copy_len]);
+            self.0.write(word_start, word).await?;
+
+            read += copy_len;
+            pos += copy_len as u32;
+        }
+
+        Ok(())
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        self.0.erase(from, to).await
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.0.flush().await
+    }
+}
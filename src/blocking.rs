@@ -0,0 +1,131 @@
+use embedded_storage::nor_flash::{MultiwriteNorFlash, NorFlash};
+
+use crate::util::yield_now;
+use crate::{Storage, WriteBehavior};
+
+/// The maximum number of bytes read in one go before yielding to the executor.
+const READ_CHUNK_SIZE: usize = 256;
+
+async fn chunked_read<S: NorFlash>(flash: &mut S, offset: u32, bytes: &mut [u8]) -> Result<(), S::Error> {
+    let mut pos = offset;
+
+    for chunk in bytes.chunks_mut(READ_CHUNK_SIZE) {
+        flash.read(pos, chunk)?;
+        pos += chunk.len() as u32;
+        yield_now().await;
+    }
+
+    Ok(())
+}
+
+async fn chunked_erase<S: NorFlash>(flash: &mut S, from: u32, to: u32) -> Result<(), S::Error> {
+    let mut pos = from;
+
+    while pos < to {
+        let end = (pos + S::ERASE_SIZE as u32).min(to);
+        flash.erase(pos, end)?;
+        pos = end;
+        yield_now().await;
+    }
+
+    Ok(())
+}
+
+/// Bridges a blocking [`NorFlash`](embedded_storage::nor_flash::NorFlash) into
+/// this crate's async [`Storage`] trait.
+///
+/// Many HAL flash drivers (nRF NVMC, RP2040 flash, etc.) only implement the
+/// synchronous `embedded-storage` traits, not the async ones. `erase` and
+/// `read` iterate in `ERASE_SIZE`/bounded chunks and yield to the executor
+/// between them, so the inherently long blocking calls don't starve other
+/// tasks. `flush` returns immediately, since the blocking calls have already
+/// completed by the time they return.
+pub struct BlockingNorFlash<S>(S);
+
+impl<S> BlockingNorFlash<S> {
+    /// Wrap a blocking `NorFlash` driver.
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+impl<S> Storage for BlockingNorFlash<S>
+where
+    S: NorFlash,
+{
+    type Error = S::Error;
+
+    const READ_SIZE: usize = S::READ_SIZE;
+    const WRITE_SIZE: usize = S::WRITE_SIZE;
+    const ERASE_SIZE: usize = S::ERASE_SIZE;
+    const ERASE_VALUE: u8 = 0xFF;
+    const WRITE_BEHAVIOR: WriteBehavior = WriteBehavior::Once;
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        chunked_read(&mut self.0, offset, bytes).await
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        chunked_erase(&mut self.0, from, to).await
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(offset, bytes)
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Bridges a blocking
+/// [`MultiwriteNorFlash`](embedded_storage::nor_flash::MultiwriteNorFlash)
+/// into this crate's async [`Storage`] trait.
+///
+/// See [`BlockingNorFlash`] for the chunking and yielding behavior applied to
+/// `erase` and `read`.
+pub struct BlockingMultiWriteNorFlash<S>(S);
+
+impl<S> BlockingMultiWriteNorFlash<S> {
+    /// Wrap a blocking `MultiwriteNorFlash` driver.
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+impl<S> Storage for BlockingMultiWriteNorFlash<S>
+where
+    S: MultiwriteNorFlash,
+{
+    type Error = S::Error;
+
+    const READ_SIZE: usize = S::READ_SIZE;
+    const WRITE_SIZE: usize = S::WRITE_SIZE;
+    const ERASE_SIZE: usize = S::ERASE_SIZE;
+    const ERASE_VALUE: u8 = 0xFF;
+    const WRITE_BEHAVIOR: WriteBehavior = WriteBehavior::TwiceAnd;
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        chunked_read(&mut self.0, offset, bytes).await
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        chunked_erase(&mut self.0, from, to).await
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(offset, bytes)
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Storage, WriteBehavior};
+
+/// Fuses two consecutive [`Storage`]s into a single, linear address space.
+///
+/// `Second` is placed immediately after `First`, so the combined
+/// [`capacity`](Storage::capacity) is the sum of both halves. Reads, writes
+/// and erases that straddle the boundary are split and dispatched to each
+/// half with rebased offsets.
+///
+/// This lets several on-chip flash regions, or an internal flash plus an
+/// external QSPI part, be treated as one logical device.
+///
+/// Since erases must stay aligned, the boundary between the two storages
+/// (i.e. `First`'s capacity) must sit on a multiple of
+/// `Concat::<First, Second>::ERASE_SIZE`, which is the max of the two
+/// halves' erase sizes.
+pub struct Concat<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<First, Second> Concat<First, Second>
+where
+    First: Storage,
+    Second: Storage<Error = First::Error>,
+{
+    /// Create a new `Concat`, placing `second` right after `first` in the address space.
+    pub fn new(first: First, second: Second) -> Self {
+        Self { first, second }
+    }
+}
+
+const fn max_usize(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+const fn same_write_behavior(a: WriteBehavior, b: WriteBehavior) -> bool {
+    matches!(
+        (a, b),
+        (WriteBehavior::Once, WriteBehavior::Once)
+            | (WriteBehavior::TwiceSecondZero, WriteBehavior::TwiceSecondZero)
+            | (WriteBehavior::TwiceAnd, WriteBehavior::TwiceAnd)
+            | (WriteBehavior::InfiniteAnd, WriteBehavior::InfiniteAnd)
+            | (WriteBehavior::InfiniteDirect, WriteBehavior::InfiniteDirect)
+    )
+}
+
+/// Splits a `[offset, offset + len)` range at `boundary`, returning the number of
+/// bytes that fall before the boundary and the rebased offset into the second half.
+fn split_at_boundary(offset: u32, len: u32, boundary: u32) -> (u32, u32) {
+    if offset >= boundary {
+        (0, offset - boundary)
+    } else {
+        (len.min(boundary - offset), 0)
+    }
+}
+
+impl<First, Second> Storage for Concat<First, Second>
+where
+    First: Storage,
+    Second: Storage<Error = First::Error>,
+{
+    type Error = First::Error;
+
+    const READ_SIZE: usize = {
+        if First::READ_SIZE != Second::READ_SIZE {
+            panic!("Concat: the two storages must have the same READ_SIZE");
+        }
+        First::READ_SIZE
+    };
+    const WRITE_SIZE: usize = {
+        if First::WRITE_SIZE != Second::WRITE_SIZE {
+            panic!("Concat: the two storages must have the same WRITE_SIZE");
+        }
+        First::WRITE_SIZE
+    };
+    const ERASE_SIZE: usize = max_usize(First::ERASE_SIZE, Second::ERASE_SIZE);
+    const ERASE_VALUE: u8 = {
+        if First::ERASE_VALUE != Second::ERASE_VALUE {
+            panic!("Concat: the two storages must have the same ERASE_VALUE");
+        }
+        First::ERASE_VALUE
+    };
+    const WRITE_BEHAVIOR: WriteBehavior = {
+        if !same_write_behavior(First::WRITE_BEHAVIOR, Second::WRITE_BEHAVIOR) {
+            panic!("Concat: the two storages must have the same WRITE_BEHAVIOR");
+        }
+        First::WRITE_BEHAVIOR
+    };
+
+    fn capacity(&self) -> usize {
+        self.first.capacity() + self.second.capacity()
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let boundary = self.first.capacity() as u32;
+        let (first_len, second_offset) = split_at_boundary(offset, bytes.len() as u32, boundary);
+        let (first_bytes, second_bytes) = bytes.split_at_mut(first_len as usize);
+
+        if !first_bytes.is_empty() {
+            self.first.read(offset, first_bytes).await?;
+        }
+        if !second_bytes.is_empty() {
+            self.second.read(second_offset, second_bytes).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        let boundary = self.first.capacity() as u32;
+
+        if from < boundary {
+            self.first.erase(from, to.min(boundary)).await?;
+        }
+        if to > boundary {
+            self.second
+                .erase(from.max(boundary) - boundary, to - boundary)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let boundary = self.first.capacity() as u32;
+        let (first_len, second_offset) = split_at_boundary(offset, bytes.len() as u32, boundary);
+        let (first_bytes, second_bytes) = bytes.split_at(first_len as usize);
+
+        if !first_bytes.is_empty() {
+            self.first.write(offset, first_bytes).await?;
+        }
+        if !second_bytes.is_empty() {
+            self.second.write(second_offset, second_bytes).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.first.flush().await?;
+        self.second.flush().await
+    }
+}
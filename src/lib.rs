@@ -1,5 +1,22 @@
 #![no_std]
 
+mod aligned;
+mod blocking;
+mod concat;
+#[cfg(feature = "std")]
+mod mem;
+mod partition;
+mod util;
+mod yielding;
+
+pub use aligned::Aligned;
+pub use blocking::{BlockingMultiWriteNorFlash, BlockingNorFlash};
+pub use concat::Concat;
+#[cfg(feature = "std")]
+pub use mem::{MemStorage, MemStorageError, INFINITE_AND, INFINITE_DIRECT, ONCE, TWICE_AND, TWICE_SECOND_ZERO};
+pub use partition::{Partition, PartitionError};
+pub use yielding::Yielding;
+
 #[allow(async_fn_in_trait)]
 pub trait Storage {
     type Error;
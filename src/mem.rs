@@ -0,0 +1,227 @@
+extern crate std;
+
+use std::vec;
+use std::vec::Vec;
+
+use crate::{Storage, WriteBehavior};
+
+/// [`MemStorage`]'s `WRITE_BEHAVIOR` const generic for [`WriteBehavior::Once`].
+pub const ONCE: u8 = 0;
+/// [`MemStorage`]'s `WRITE_BEHAVIOR` const generic for [`WriteBehavior::TwiceSecondZero`].
+pub const TWICE_SECOND_ZERO: u8 = 1;
+/// [`MemStorage`]'s `WRITE_BEHAVIOR` const generic for [`WriteBehavior::TwiceAnd`].
+pub const TWICE_AND: u8 = 2;
+/// [`MemStorage`]'s `WRITE_BEHAVIOR` const generic for [`WriteBehavior::InfiniteAnd`].
+pub const INFINITE_AND: u8 = 3;
+/// [`MemStorage`]'s `WRITE_BEHAVIOR` const generic for [`WriteBehavior::InfiniteDirect`].
+pub const INFINITE_DIRECT: u8 = 4;
+
+const fn decode_write_behavior(behavior: u8) -> WriteBehavior {
+    match behavior {
+        ONCE => WriteBehavior::Once,
+        TWICE_SECOND_ZERO => WriteBehavior::TwiceSecondZero,
+        TWICE_AND => WriteBehavior::TwiceAnd,
+        INFINITE_AND => WriteBehavior::InfiniteAnd,
+        INFINITE_DIRECT => WriteBehavior::InfiniteDirect,
+        _ => panic!("MemStorage: WRITE_BEHAVIOR must be one of the mem::* constants"),
+    }
+}
+
+/// An error returned by [`MemStorage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemStorageError {
+    /// An `offset`/`len` was not aligned to the relevant granularity const.
+    Unaligned,
+    /// The requested address range falls outside of the configured capacity.
+    OutOfBounds,
+    /// A byte was written more times than `WRITE_BEHAVIOR` allows without an erase in between.
+    TooManyWrites,
+    /// The second write under [`WriteBehavior::TwiceSecondZero`] was not all zeros.
+    SecondWriteNotZero,
+}
+
+/// A simulated power-loss during an upcoming [`MemStorage::erase`]: the bytes in
+/// `range` are left in a pseudo-random, undefined state instead of `ERASE_VALUE`.
+struct PowerLoss {
+    range: core::ops::Range<usize>,
+    rng_state: u64,
+}
+
+/// A simple, seedable xorshift64 PRNG, used only to produce the "undefined"
+/// contents of a sector whose erase was interrupted by a simulated power loss.
+fn next_random_byte(state: &mut u64) -> u8 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    (*state >> 24) as u8
+}
+
+/// An in-memory [`Storage`] simulator, backed by a `Vec<u8>`.
+///
+/// `MemStorage` is parameterized by the `READ_SIZE`, `WRITE_SIZE`,
+/// `ERASE_SIZE` and `ERASE_VALUE` it exposes, plus a `WRITE_BEHAVIOR` const
+/// generic set to one of the [`ONCE`], [`TWICE_SECOND_ZERO`],
+/// [`TWICE_AND`], [`INFINITE_AND`] or [`INFINITE_DIRECT`] constants (an
+/// associated const can't itself be generic over [`WriteBehavior`], since
+/// that type isn't a valid const-generic parameter on stable Rust).
+///
+/// Unlike a real driver, `MemStorage` *enforces* the semantics the
+/// [`Storage`] trait only documents: it tracks per-byte write counts and
+/// returns [`MemStorageError::TooManyWrites`] on a write beyond what
+/// `WRITE_BEHAVIOR` allows, returns [`MemStorageError::SecondWriteNotZero`]
+/// if the second write under `TwiceSecondZero` isn't all zeros, applies a
+/// bitwise AND for the AND variants, overwrites directly for
+/// `InfiniteDirect`, and returns [`MemStorageError::Unaligned`] if any
+/// `offset`/`len` isn't aligned to the configured granularity. This lets
+/// downstream crates unit-test against every `WriteBehavior` without real
+/// hardware.
+pub struct MemStorage<
+    const READ_SIZE: usize,
+    const WRITE_SIZE: usize,
+    const ERASE_SIZE: usize,
+    const ERASE_VALUE: u8,
+    const WRITE_BEHAVIOR: u8,
+> {
+    data: Vec<u8>,
+    write_counts: Vec<u8>,
+    power_loss: Option<PowerLoss>,
+}
+
+impl<
+        const READ_SIZE: usize,
+        const WRITE_SIZE: usize,
+        const ERASE_SIZE: usize,
+        const ERASE_VALUE: u8,
+        const WRITE_BEHAVIOR: u8,
+    > MemStorage<READ_SIZE, WRITE_SIZE, ERASE_SIZE, ERASE_VALUE, WRITE_BEHAVIOR>
+{
+    /// Create a new, fully erased `MemStorage` of the given `capacity`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            data: vec![ERASE_VALUE; capacity],
+            write_counts: vec![0; capacity],
+            power_loss: None,
+        }
+    }
+
+    /// Arrange for the next [`erase`](Storage::erase) call whose range covers
+    /// `range` to simulate a power loss: instead of being cleanly set to
+    /// `ERASE_VALUE`, the bytes in `range` are left in a pseudo-randomized
+    /// state, matching the documented "contents undefined" guarantee. This
+    /// lets downstream crates test crash-consistency.
+    pub fn simulate_power_loss(&mut self, range: core::ops::Range<usize>, seed: u64) {
+        self.power_loss = Some(PowerLoss { range, rng_state: seed | 1 });
+    }
+}
+
+impl<
+        const READ_SIZE: usize,
+        const WRITE_SIZE: usize,
+        const ERASE_SIZE: usize,
+        const ERASE_VALUE: u8,
+        const WRITE_BEHAVIOR: u8,
+    > Storage for MemStorage<READ_SIZE, WRITE_SIZE, ERASE_SIZE, ERASE_VALUE, WRITE_BEHAVIOR>
+{
+    type Error = MemStorageError;
+
+    const READ_SIZE: usize = READ_SIZE;
+    const WRITE_SIZE: usize = WRITE_SIZE;
+    const ERASE_SIZE: usize = ERASE_SIZE;
+    const ERASE_VALUE: u8 = ERASE_VALUE;
+    const WRITE_BEHAVIOR: WriteBehavior = decode_write_behavior(WRITE_BEHAVIOR);
+
+    fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        if offset as usize % Self::READ_SIZE != 0 || bytes.len() % Self::READ_SIZE != 0 {
+            return Err(MemStorageError::Unaligned);
+        }
+        if offset as usize + bytes.len() > self.data.len() {
+            return Err(MemStorageError::OutOfBounds);
+        }
+
+        bytes.copy_from_slice(&self.data[offset as usize..offset as usize + bytes.len()]);
+
+        Ok(())
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        if from as usize % Self::ERASE_SIZE != 0 || to as usize % Self::ERASE_SIZE != 0 {
+            return Err(MemStorageError::Unaligned);
+        }
+        if to as usize > self.data.len() {
+            return Err(MemStorageError::OutOfBounds);
+        }
+
+        let range = from as usize..to as usize;
+
+        match &mut self.power_loss {
+            Some(power_loss) if power_loss.range.start >= range.start && power_loss.range.end <= range.end => {
+                for i in range.clone() {
+                    self.data[i] = if power_loss.range.contains(&i) {
+                        next_random_byte(&mut power_loss.rng_state)
+                    } else {
+                        ERASE_VALUE
+                    };
+                }
+                self.power_loss = None;
+            }
+            _ => self.data[range.clone()].fill(ERASE_VALUE),
+        }
+
+        self.write_counts[range].fill(0);
+
+        Ok(())
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        if offset as usize % Self::WRITE_SIZE != 0 || bytes.len() % Self::WRITE_SIZE != 0 {
+            return Err(MemStorageError::Unaligned);
+        }
+        if offset as usize + bytes.len() > self.data.len() {
+            return Err(MemStorageError::OutOfBounds);
+        }
+
+        let start = offset as usize;
+        // `None` means writes are unbounded (InfiniteAnd/InfiniteDirect), so the
+        // per-byte write count is neither checked nor tracked for those variants,
+        // and can never spuriously saturate.
+        let max_writes = match decode_write_behavior(WRITE_BEHAVIOR) {
+            WriteBehavior::Once => Some(1),
+            WriteBehavior::TwiceSecondZero | WriteBehavior::TwiceAnd => Some(2),
+            WriteBehavior::InfiniteAnd | WriteBehavior::InfiniteDirect => None,
+        };
+
+        for (i, &new_byte) in bytes.iter().enumerate() {
+            let index = start + i;
+
+            if let Some(max_writes) = max_writes {
+                if self.write_counts[index] >= max_writes {
+                    return Err(MemStorageError::TooManyWrites);
+                }
+            }
+            if decode_write_behavior(WRITE_BEHAVIOR) == WriteBehavior::TwiceSecondZero
+                && self.write_counts[index] == 1
+                && new_byte != 0
+            {
+                return Err(MemStorageError::SecondWriteNotZero);
+            }
+
+            self.data[index] = match decode_write_behavior(WRITE_BEHAVIOR) {
+                WriteBehavior::InfiniteDirect => new_byte,
+                _ => self.data[index] & new_byte,
+            };
+            if max_writes.is_some() {
+                self.write_counts[index] = self.write_counts[index].saturating_add(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
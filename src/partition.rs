@@ -0,0 +1,112 @@
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::mutex::Mutex;
+
+use crate::{Storage, WriteBehavior};
+
+/// An error returned by [`Partition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionError<E> {
+    /// The requested `offset`/`size` is not aligned to the inner storage's `ERASE_SIZE`.
+    Unaligned,
+    /// The requested address range falls outside of the partition.
+    OutOfBounds,
+    /// An error occurred in the underlying storage.
+    Inner(E),
+}
+
+/// A view over a sub-range of a [`Storage`] that is shared behind a `Mutex`.
+///
+/// `Partition` holds a reference to a `Mutex<M, S>` plus an `offset` and
+/// `size`, and implements [`Storage`] by translating every `read`/`write`/
+/// `erase`/`flush` into a locked operation on the sub-range
+/// `[offset, offset + size)` of the underlying storage. This lets one
+/// physical device back several independent consumers (e.g. a config store
+/// and a log) without them needing to coordinate addresses, while the mutex
+/// serializes concurrent async access.
+pub struct Partition<'a, M: RawMutex, S> {
+    storage: &'a Mutex<M, S>,
+    offset: usize,
+    size: usize,
+}
+
+impl<'a, M, S> Partition<'a, M, S>
+where
+    M: RawMutex,
+    S: Storage,
+{
+    /// Create a new `Partition` covering `[offset, offset + size)` of `storage`.
+    ///
+    /// Returns [`PartitionError::Unaligned`] if `offset` or `size` is not a
+    /// multiple of `S::ERASE_SIZE`.
+    pub fn new(storage: &'a Mutex<M, S>, offset: usize, size: usize) -> Result<Self, PartitionError<S::Error>> {
+        if offset % S::ERASE_SIZE != 0 || size % S::ERASE_SIZE != 0 {
+            return Err(PartitionError::Unaligned);
+        }
+
+        Ok(Self { storage, offset, size })
+    }
+
+    fn check_bounds(&self, offset: u32, len: usize) -> Result<(), PartitionError<S::Error>> {
+        if offset as usize + len > self.size {
+            Err(PartitionError::OutOfBounds)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_erase_range(&self, from: u32, to: u32) -> Result<(), PartitionError<S::Error>> {
+        if to < from {
+            return Err(PartitionError::OutOfBounds);
+        }
+        if from as usize % S::ERASE_SIZE != 0 || to as usize % S::ERASE_SIZE != 0 {
+            return Err(PartitionError::Unaligned);
+        }
+
+        self.check_bounds(from, (to - from) as usize)
+    }
+}
+
+impl<'a, M, S> Storage for Partition<'a, M, S>
+where
+    M: RawMutex,
+    S: Storage,
+{
+    type Error = PartitionError<S::Error>;
+
+    const READ_SIZE: usize = S::READ_SIZE;
+    const WRITE_SIZE: usize = S::WRITE_SIZE;
+    const ERASE_SIZE: usize = S::ERASE_SIZE;
+    const ERASE_VALUE: u8 = S::ERASE_VALUE;
+    const WRITE_BEHAVIOR: WriteBehavior = S::WRITE_BEHAVIOR;
+
+    fn capacity(&self) -> usize {
+        self.size
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let base = self.offset as u32 + offset;
+        self.storage.lock().await.read(base, bytes).await.map_err(PartitionError::Inner)
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        self.check_erase_range(from, to)?;
+        let base = self.offset as u32;
+        self.storage
+            .lock()
+            .await
+            .erase(base + from, base + to)
+            .await
+            .map_err(PartitionError::Inner)
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let base = self.offset as u32 + offset;
+        self.storage.lock().await.write(base, bytes).await.map_err(PartitionError::Inner)
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.storage.lock().await.flush().await.map_err(PartitionError::Inner)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::util::yield_now;
+use crate::{Storage, WriteBehavior};
+
+/// The maximum number of bytes read in one go before yielding to the executor.
+const READ_CHUNK_SIZE: usize = 256;
+
+/// Wraps a [`Storage`] and cooperatively yields to the async executor while
+/// performing long blocking operations, so other tasks (e.g. one that feeds a
+/// watchdog) keep running.
+///
+/// `erase` is split into `S::ERASE_SIZE` chunks, awaiting one sector at a
+/// time and yielding in between. Large `read`s are likewise split into
+/// bounded slices. `write` and `flush` are forwarded directly, since they're
+/// expected to operate on much smaller amounts of data.
+pub struct Yielding<S>(S);
+
+impl<S> Yielding<S> {
+    /// Wrap `inner` so its long operations yield to the executor.
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+impl<S: Storage> Storage for Yielding<S> {
+    type Error = S::Error;
+
+    const READ_SIZE: usize = S::READ_SIZE;
+    const WRITE_SIZE: usize = S::WRITE_SIZE;
+    const ERASE_SIZE: usize = S::ERASE_SIZE;
+    const ERASE_VALUE: u8 = S::ERASE_VALUE;
+    const WRITE_BEHAVIOR: WriteBehavior = S::WRITE_BEHAVIOR;
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let mut pos = offset;
+
+        for chunk in bytes.chunks_mut(READ_CHUNK_SIZE) {
+            self.0.read(pos, chunk).await?;
+            pos += chunk.len() as u32;
+            yield_now().await;
+        }
+
+        Ok(())
+    }
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        let mut pos = from;
+
+        while pos < to {
+            let end = (pos + Self::ERASE_SIZE as u32).min(to);
+            self.0.erase(pos, end).await?;
+            pos = end;
+            yield_now().await;
+        }
+
+        Ok(())
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(offset, bytes).await
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.0.flush().await
+    }
+}